@@ -23,6 +23,437 @@
 pub enum Error {
     Timeout,
     InvalidResponse,
+    /// All retry attempts were exhausted while the remote host kept responding with
+    /// HTTP 429 or 5xx, distinct from [`Error::Timeout`] which covers connection failures.
+    RateLimited,
+}
+
+/// Progress events reported across the scraping pipeline, so a caller can render a live
+/// progress bar or counters without this crate depending on any particular UI.
+pub mod progress {
+    use std::time::Duration;
+
+    /// A single step of progress made by the scraping pipeline.
+    #[derive(Debug, Clone)]
+    pub enum ProgressEvent {
+        /// A search engine page finished loading.
+        SearchPageDone { page: usize, links_found: usize },
+        /// A referring page was resolved into gleam.io links.
+        PageResolved { url: String, gleam_links: usize },
+        /// A gleam.io giveaway finished loading.
+        GiveawayFetched { gleam_id: String },
+        /// A request is about to be retried after a retriable failure.
+        Retrying { url: String, attempt: u32 },
+        /// A request was rate-limited and will be retried after `wait`.
+        RateLimited { url: String, wait: Duration },
+    }
+
+    /// Receives [`ProgressEvent`]s emitted by the scraping pipeline.
+    pub trait ProgressObserver {
+        fn on_event(&mut self, event: ProgressEvent);
+    }
+
+    impl<F: FnMut(ProgressEvent)> ProgressObserver for F {
+        fn on_event(&mut self, event: ProgressEvent) {
+            self(event)
+        }
+    }
+}
+
+/// A persistent scraping session: keeps cookies between requests to the same host, rotates
+/// the `User-Agent` header per request, and retries retriable failures with full-jitter
+/// exponential backoff instead of giving up on the first soft block.
+pub mod session {
+    use super::progress::{ProgressEvent, ProgressObserver};
+    use super::Error;
+    use rand::Rng;
+    use std::collections::HashMap;
+    use std::thread::sleep;
+    use std::time::Duration;
+    use string_tools::get_all_between;
+
+    const USER_AGENTS: &[&str] = &[
+        "Mozilla/5.0 (X11; Linux x86_64; rv:71.0) Gecko/20100101 Firefox/71.0",
+        "Mozilla/5.0 (X11; Ubuntu; Linux x86_64; rv:78.0) Gecko/20100101 Firefox/78.0",
+        "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36",
+        "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/14.1.1 Safari/605.1.15",
+    ];
+
+    /// Full-jitter exponential backoff policy applied to retriable failures (timeouts and
+    /// HTTP 429/5xx responses): `sleep(random(0, min(cap, base * 2^attempt)))` before retrying.
+    #[derive(Debug, Clone)]
+    pub struct RetryPolicy {
+        /// Total number of requests attempted, including the initial one, before giving up.
+        pub max_attempts: u32,
+        pub base: Duration,
+        pub cap: Duration,
+    }
+
+    impl Default for RetryPolicy {
+        fn default() -> Self {
+            RetryPolicy {
+                max_attempts: 5,
+                base: Duration::from_millis(500),
+                cap: Duration::from_secs(30),
+            }
+        }
+    }
+
+    impl RetryPolicy {
+        /// The upper bound `backoff` jitters within for `attempt`: `min(cap, base * 2^attempt)`.
+        fn max_wait(&self, attempt: u32) -> Duration {
+            let exp = self.base.as_millis().saturating_mul(1u128 << attempt.min(32));
+            Duration::from_millis(exp.min(self.cap.as_millis()) as u64)
+        }
+
+        fn backoff(&self, attempt: u32) -> Duration {
+            let capped = self.max_wait(attempt).as_millis().max(1);
+            Duration::from_millis(rand::thread_rng().gen_range(0..=capped) as u64)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn max_wait_grows_exponentially_until_capped() {
+            let policy = RetryPolicy {
+                max_attempts: 5,
+                base: Duration::from_millis(100),
+                cap: Duration::from_secs(10),
+            };
+            assert_eq!(policy.max_wait(0), Duration::from_millis(100));
+            assert_eq!(policy.max_wait(1), Duration::from_millis(200));
+            assert_eq!(policy.max_wait(2), Duration::from_millis(400));
+            assert_eq!(policy.max_wait(10), policy.cap);
+        }
+
+        #[test]
+        fn backoff_never_exceeds_max_wait() {
+            let policy = RetryPolicy {
+                max_attempts: 5,
+                base: Duration::from_millis(50),
+                cap: Duration::from_millis(400),
+            };
+            for attempt in 0..8 {
+                let bound = policy.max_wait(attempt).max(Duration::from_millis(1));
+                for _ in 0..50 {
+                    assert!(policy.backoff(attempt) <= bound);
+                }
+            }
+        }
+    }
+
+    /// Holds cookies and a retry policy across many requests.
+    /// Build one and reuse it for every call in a scraping run instead of a one-shot request.
+    pub struct Session {
+        cookies: HashMap<String, HashMap<String, String>>,
+        pub retry_policy: RetryPolicy,
+        observer: Option<Box<dyn ProgressObserver>>,
+    }
+
+    impl Session {
+        pub fn new() -> Session {
+            Session {
+                cookies: HashMap::new(),
+                retry_policy: RetryPolicy::default(),
+                observer: None,
+            }
+        }
+
+        /// Report [`ProgressEvent`]s emitted while retrying requests to `observer`. Accepts
+        /// any [`ProgressObserver`](super::progress::ProgressObserver), including a plain
+        /// closure via its blanket implementation.
+        pub fn set_observer(&mut self, observer: impl ProgressObserver + 'static) {
+            self.observer = Some(Box::new(observer));
+        }
+
+        fn emit(&mut self, event: ProgressEvent) {
+            self.notify(event);
+        }
+
+        /// Forward a [`ProgressEvent`] to the observer set with [`Session::set_observer`],
+        /// if any. Used internally for retry/rate-limit events, and by
+        /// [`pipeline::run`](crate::pipeline::run) to report the rest of the pipeline through
+        /// the same observer.
+        pub fn notify(&mut self, event: ProgressEvent) {
+            if let Some(observer) = &mut self.observer {
+                observer.on_event(event);
+            }
+        }
+
+        fn random_user_agent() -> &'static str {
+            USER_AGENTS[rand::thread_rng().gen_range(0..USER_AGENTS.len())]
+        }
+
+        /// Perform a GET request to `url` with `extra_headers` layered on top of the
+        /// session-managed `Host`, `User-Agent` and `Cookie` headers, retrying retriable
+        /// failures with backoff for at most `retry_policy.max_attempts` total requests
+        /// (the initial attempt included).
+        pub fn get(&mut self, url: &str, extra_headers: &[(&str, &str)]) -> Result<String, Error> {
+            let host = get_all_between(url, "://", "/").to_string();
+            let mut attempt = 0;
+            loop {
+                let mut request = minreq::get(url)
+                    .with_header("Host", &host)
+                    .with_header("User-Agent", Self::random_user_agent());
+                for (name, value) in extra_headers {
+                    request = request.with_header(*name, *value);
+                }
+                if let Some(jar) = self.cookies.get(&host) {
+                    if !jar.is_empty() {
+                        let cookie_header = jar
+                            .iter()
+                            .map(|(name, value)| {
+                                if value.is_empty() {
+                                    name.clone()
+                                } else {
+                                    format!("{}={}", name, value)
+                                }
+                            })
+                            .collect::<Vec<_>>()
+                            .join("; ");
+                        request = request.with_header("Cookie", cookie_header);
+                    }
+                }
+
+                match request.send() {
+                    Ok(response) => {
+                        if let Some(cookie) = response.headers.get("set-cookie") {
+                            let cookie = cookie.split(';').next().unwrap_or(cookie);
+                            let (name, value) = match cookie.split_once('=') {
+                                Some((name, value)) => (name.to_string(), value.to_string()),
+                                None => (cookie.to_string(), String::new()),
+                            };
+                            self.cookies.entry(host.clone()).or_default().insert(name, value);
+                        }
+                        if response.status_code == 429 || response.status_code >= 500 {
+                            if attempt + 1 >= self.retry_policy.max_attempts {
+                                return Err(Error::RateLimited);
+                            }
+                            let wait = response
+                                .headers
+                                .get("retry-after")
+                                .and_then(|v| v.parse().ok())
+                                .map(Duration::from_secs)
+                                .unwrap_or_else(|| self.retry_policy.backoff(attempt));
+                            self.emit(ProgressEvent::RateLimited {
+                                url: url.to_string(),
+                                wait,
+                            });
+                            sleep(wait);
+                            attempt += 1;
+                            continue;
+                        }
+                        return response
+                            .as_str()
+                            .map(|s| s.to_string())
+                            .map_err(|_| Error::InvalidResponse);
+                    }
+                    Err(_) => {
+                        if attempt + 1 >= self.retry_policy.max_attempts {
+                            return Err(Error::Timeout);
+                        }
+                        self.emit(ProgressEvent::Retrying {
+                            url: url.to_string(),
+                            attempt,
+                        });
+                        sleep(self.retry_policy.backoff(attempt));
+                        attempt += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    impl Default for Session {
+        fn default() -> Self {
+            Session::new()
+        }
+    }
+}
+
+/// An in-memory TTL cache for resolved intermediary links and fetched giveaways, so the
+/// pipeline doesn't re-request a gleam.io giveaway or referring page it has already seen
+/// within the same run. Requires the `cache` feature.
+#[cfg(feature = "cache")]
+pub mod cache {
+    use crate::gleam::Giveaway;
+    use std::collections::HashMap;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    #[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+    struct Entry<T> {
+        value: T,
+        inserted_at: u64,
+    }
+
+    fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+
+    /// Caches fetched giveaways by `gleam_id` and intermediary resolutions by source url,
+    /// each entry expiring `ttl` seconds after it was produced.
+    #[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Cache {
+        ttl: Duration,
+        giveaways: HashMap<String, Entry<Giveaway>>,
+        resolutions: HashMap<String, Entry<Vec<String>>>,
+    }
+
+    impl Cache {
+        pub fn new(ttl: Duration) -> Cache {
+            Cache {
+                ttl,
+                giveaways: HashMap::new(),
+                resolutions: HashMap::new(),
+            }
+        }
+
+        /// Return the cached giveaway for `gleam_id`, if present and its `update_date` is
+        /// still within `ttl`.
+        pub fn get_giveaway(&self, gleam_id: &str) -> Option<&Giveaway> {
+            self.giveaways.get(gleam_id).and_then(|entry| {
+                if now().saturating_sub(entry.value.update_date) < self.ttl.as_secs() {
+                    Some(&entry.value)
+                } else {
+                    None
+                }
+            })
+        }
+
+        pub fn insert_giveaway(&mut self, giveaway: Giveaway) {
+            self.giveaways.insert(
+                giveaway.gleam_id.clone(),
+                Entry {
+                    inserted_at: now(),
+                    value: giveaway,
+                },
+            );
+        }
+
+        /// Return the cached resolution for `url`, if present and inserted within `ttl`.
+        pub fn get_resolution(&self, url: &str) -> Option<&Vec<String>> {
+            self.resolutions.get(url).and_then(|entry| {
+                if now().saturating_sub(entry.inserted_at) < self.ttl.as_secs() {
+                    Some(&entry.value)
+                } else {
+                    None
+                }
+            })
+        }
+
+        pub fn insert_resolution(&mut self, url: String, links: Vec<String>) {
+            self.resolutions.insert(
+                url,
+                Entry {
+                    inserted_at: now(),
+                    value: links,
+                },
+            );
+        }
+
+        /// Drop a cached giveaway, forcing the next fetch to hit the network.
+        pub fn invalidate(&mut self, gleam_id: &str) {
+            self.giveaways.remove(gleam_id);
+        }
+
+        /// Remove every entry whose TTL has elapsed.
+        pub fn purge_expired(&mut self) {
+            let ttl = self.ttl.as_secs();
+            let n = now();
+            self.giveaways
+                .retain(|_, entry| n.saturating_sub(entry.value.update_date) < ttl);
+            self.resolutions
+                .retain(|_, entry| n.saturating_sub(entry.inserted_at) < ttl);
+        }
+    }
+
+    /// On-disk JSON persistence, reusing the `serde-support` feature.
+    #[cfg(feature = "serde-support")]
+    impl Cache {
+        /// Persist the cache to `path` as JSON.
+        pub fn save_to_file(&self, path: &std::path::Path) -> std::io::Result<()> {
+            let file = std::fs::File::create(path)?;
+            serde_json::to_writer(file, self)
+                .map_err(std::io::Error::other)
+        }
+
+        /// Load a cache previously written by [`Cache::save_to_file`].
+        pub fn load_from_file(path: &std::path::Path) -> std::io::Result<Cache> {
+            let file = std::fs::File::open(path)?;
+            serde_json::from_reader(file)
+                .map_err(std::io::Error::other)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn stub_giveaway(gleam_id: &str, update_date: u64) -> Giveaway {
+            Giveaway {
+                gleam_id: gleam_id.to_string(),
+                entry_count: None,
+                entry_methods: Vec::new(),
+                start_date: 0,
+                end_date: 0,
+                update_date,
+                name: String::new(),
+                description: String::new(),
+            }
+        }
+
+        #[test]
+        fn get_giveaway_returns_fresh_entry() {
+            let mut cache = Cache::new(Duration::from_secs(60));
+            cache.insert_giveaway(stub_giveaway("abcde", now()));
+            assert!(cache.get_giveaway("abcde").is_some());
+        }
+
+        #[test]
+        fn get_giveaway_expires_after_ttl() {
+            let mut cache = Cache::new(Duration::from_secs(60));
+            cache.insert_giveaway(stub_giveaway("abcde", now().saturating_sub(120)));
+            assert!(cache.get_giveaway("abcde").is_none());
+        }
+
+        #[test]
+        fn get_resolution_expires_after_ttl() {
+            let mut cache = Cache::new(Duration::from_secs(60));
+            cache.insert_resolution("https://example.com".to_string(), vec!["a".to_string()]);
+            assert!(cache.get_resolution("https://example.com").is_some());
+
+            cache.resolutions.get_mut("https://example.com").unwrap().inserted_at = now().saturating_sub(120);
+            assert!(cache.get_resolution("https://example.com").is_none());
+        }
+
+        #[test]
+        fn invalidate_removes_giveaway() {
+            let mut cache = Cache::new(Duration::from_secs(60));
+            cache.insert_giveaway(stub_giveaway("abcde", now()));
+            cache.invalidate("abcde");
+            assert!(cache.get_giveaway("abcde").is_none());
+        }
+
+        #[test]
+        fn purge_expired_drops_only_stale_entries() {
+            let mut cache = Cache::new(Duration::from_secs(60));
+            cache.insert_giveaway(stub_giveaway("fresh", now()));
+            cache.insert_giveaway(stub_giveaway("stale", now().saturating_sub(120)));
+            cache.insert_resolution("https://fresh.example".to_string(), vec!["a".to_string()]);
+
+            cache.purge_expired();
+
+            assert!(cache.giveaways.contains_key("fresh"));
+            assert!(!cache.giveaways.contains_key("stale"));
+            assert!(cache.resolutions.contains_key("https://fresh.example"));
+        }
+    }
 }
 
 /// Contains functions related to google pages parsing.
@@ -30,16 +461,28 @@ pub mod google {
     use super::Error;
     use string_tools::{get_all_after, get_all_between_strict};
 
-    fn get_full_url(page: usize) -> String {
+    pub(crate) fn get_full_url(page: usize) -> String {
         format!(
             "https://www.google.com/search?q=\"gleam.io\"&tbs=qdr:h&filter=0&start={}",
             page * 10
         )
     }
 
-    /// Search google for a something and returns result urls.  
-    /// See [Google Advanced Search](https://www.google.com/advanced_search) for more information about request syntax.  
-    /// Only one page is loaded.  
+    /// Extract result links out of a fetched google results page body.
+    pub(crate) fn extract_links(mut body: &str) -> Vec<String> {
+        let mut rep = Vec::new();
+        while let Some(url) = get_all_between_strict(body, "\"><a href=\"", "\"") {
+            body = get_all_after(body, url);
+            if body.starts_with("\" onmousedown=\"return rwt(") || body.starts_with("\" data-ved=\"2a") {
+                rep.push(url.to_string());
+            }
+        }
+        rep
+    }
+
+    /// Search google for a something and returns result urls.
+    /// See [Google Advanced Search](https://www.google.com/advanced_search) for more information about request syntax.
+    /// Only one page is loaded.
     /// # Examples
     /// ```
     /// use gleam_finder::google;
@@ -48,30 +491,128 @@ pub mod google {
     /// let links = google::search(0);
     /// ```
     pub fn search(page: usize) -> Result<Vec<String>, Error> {
-        if let Ok(response) = minreq::get(get_full_url(page))
-            .with_header("Accept", "text/plain")
-            .with_header("Host", "www.google.com")
+        crate::search::search_with(&crate::search::GoogleEngine, page)
+    }
+
+    /// Same as [`search`], but routed through a [`Session`](crate::session::Session) so
+    /// cookies are kept, the `User-Agent` rotates, and soft blocks (429/5xx) are retried
+    /// with backoff instead of immediately failing.
+    pub fn search_with_session(
+        session: &mut crate::session::Session,
+        page: usize,
+    ) -> Result<Vec<String>, Error> {
+        let body = session.get(&get_full_url(page), &[("Accept", "text/plain")])?;
+        Ok(extract_links(&body))
+    }
+
+    /// Async equivalent of [`search`], backed by `reqwest` instead of `minreq`.
+    /// Requires the `async` feature.
+    #[cfg(feature = "async")]
+    pub async fn search_async(page: usize) -> Result<Vec<String>, Error> {
+        let response = reqwest::Client::new()
+            .get(get_full_url(page))
+            .header("Accept", "text/plain")
+            .header("Host", "www.google.com")
+            .header(
+                "User-Agent",
+                "Mozilla/5.0 (X11; Linux x86_64; rv:71.0) Gecko/20100101 Firefox/71.0",
+            )
+            .send()
+            .await
+            .map_err(|_| Error::Timeout)?;
+
+        let body = response.text().await.map_err(|_| Error::InvalidResponse)?;
+        Ok(extract_links(&body))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn get_full_url_test() {
+            assert_eq!(
+                "https://www.google.com/search?q=\"gleam.io\"&tbs=qdr:h&filter=0&start=10",
+                get_full_url(1)
+            );
+        }
+
+        #[test]
+        fn resolve_google_request() {
+            let result = search(0).unwrap();
+            assert!(!result.is_empty());
+
+            let result = search(9).unwrap();
+            assert!(result.is_empty());
+        }
+    }
+}
+
+/// Pluggable search backends used to discover pages referencing gleam.io, so the whole
+/// crate doesn't go dark when a single engine changes its markup or starts rate-limiting.
+pub mod search {
+    use super::Error;
+    use string_tools::{get_all_after, get_all_between, get_all_between_strict};
+
+    /// A web search backend capable of building a query for a result page and extracting
+    /// the links out of the HTML it returns.
+    pub trait SearchEngine {
+        /// Build the URL to request for the given zero-indexed result page.
+        fn query_url(&self, page: usize) -> String;
+
+        /// Extract result links out of a fetched page body.
+        fn extract_links(&self, body: &str) -> Vec<String>;
+    }
+
+    /// Searches Google for pages mentioning "gleam.io" in the last hour.
+    pub struct GoogleEngine;
+
+    impl SearchEngine for GoogleEngine {
+        fn query_url(&self, page: usize) -> String {
+            crate::google::get_full_url(page)
+        }
+
+        fn extract_links(&self, body: &str) -> Vec<String> {
+            crate::google::extract_links(body)
+        }
+    }
+
+    /// Searches the Bing HTML endpoint for pages mentioning "gleam.io" in the last hour.
+    pub struct BingEngine;
+
+    impl SearchEngine for BingEngine {
+        fn query_url(&self, page: usize) -> String {
+            format!(
+                "https://www.bing.com/search?q=\"gleam.io\"&qft=interval%3d%227%22&first={}",
+                page * 10 + 1
+            )
+        }
+
+        fn extract_links(&self, mut body: &str) -> Vec<String> {
+            let mut rep = Vec::new();
+            while let Some(url) = get_all_between_strict(body, "<h2><a href=\"", "\"") {
+                body = get_all_after(body, url);
+                rep.push(url.to_string());
+            }
+            rep
+        }
+    }
+
+    /// Run a single engine against one result page and return the links it found.
+    pub fn search_with(engine: &dyn SearchEngine, page: usize) -> Result<Vec<String>, Error> {
+        let url = engine.query_url(page);
+        let host = get_all_between(&url, "://", "/").to_string();
+        if let Ok(response) = minreq::get(&url)
+            .with_header("Accept", "text/html,text/plain")
+            .with_header("Host", &host)
             .with_header(
                 "User-Agent",
                 "Mozilla/5.0 (X11; Linux x86_64; rv:71.0) Gecko/20100101 Firefox/71.0",
             )
             .send()
         {
-            if let Ok(mut body) = response.as_str() {
-                /*use std::io::prelude::*;  // useful for debugging
-                use std::fs::File;
-                let mut file = File::create(format!("page{}.html", page)).unwrap();
-                file.write_all(body.as_bytes()).unwrap();*/
-                let mut rep = Vec::new();
-                while let Some(url) =
-                    get_all_between_strict(body, "\"><a href=\"", "\"")
-                {
-                    body = get_all_after(body, url);
-                    if body.starts_with("\" onmousedown=\"return rwt(") || body.starts_with("\" data-ved=\"2a") {
-                        rep.push(url.to_string());
-                    }
-                }
-                Ok(rep)
+            if let Ok(body) = response.as_str() {
+                Ok(engine.extract_links(body))
             } else {
                 Err(Error::InvalidResponse)
             }
@@ -80,25 +621,109 @@ pub mod google {
         }
     }
 
+    /// Merge per-engine results, removing duplicates while preserving first-seen order.
+    /// An engine that errored contributes nothing; the merge only fails if every engine did.
+    fn merge_results(results: Vec<Result<Vec<String>, Error>>) -> Result<Vec<String>, Error> {
+        let mut merged = Vec::new();
+        let mut last_err = None;
+        let mut any_ok = false;
+        for result in results {
+            match result {
+                Ok(links) => {
+                    any_ok = true;
+                    for link in links {
+                        if !merged.contains(&link) {
+                            merged.push(link);
+                        }
+                    }
+                }
+                Err(e) => last_err = Some(e),
+            }
+        }
+        if !any_ok {
+            if let Some(err) = last_err {
+                return Err(err);
+            }
+        }
+        Ok(merged)
+    }
+
+    /// Query every engine for the given page and merge their result urls, removing duplicates.
+    /// An engine that fails (e.g. because it changed its markup or started rate-limiting) is
+    /// skipped rather than aborting the whole call; this only fails if every engine does.
+    pub fn search_all(
+        engines: &[Box<dyn SearchEngine>],
+        page: usize,
+    ) -> Result<Vec<String>, Error> {
+        let results = engines
+            .iter()
+            .map(|engine| search_with(engine.as_ref(), page))
+            .collect();
+        merge_results(results)
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
 
+        struct StubEngine(Vec<String>);
+
+        impl SearchEngine for StubEngine {
+            fn query_url(&self, _page: usize) -> String {
+                String::new()
+            }
+
+            fn extract_links(&self, _body: &str) -> Vec<String> {
+                self.0.clone()
+            }
+        }
+
         #[test]
-        fn get_full_url_test() {
+        fn google_engine_query_url() {
             assert_eq!(
                 "https://www.google.com/search?q=\"gleam.io\"&tbs=qdr:h&filter=0&start=10",
-                get_full_url(1)
+                GoogleEngine.query_url(1)
             );
         }
 
         #[test]
-        fn resolve_google_request() {
-            let result = search(0).unwrap();
-            assert!(!result.is_empty());
+        fn bing_engine_query_url() {
+            assert_eq!(
+                "https://www.bing.com/search?q=\"gleam.io\"&qft=interval%3d%227%22&first=11",
+                BingEngine.query_url(1)
+            );
+        }
 
-            let result = search(9).unwrap();
-            assert!(result.is_empty());
+        #[test]
+        fn merge_results_dedupes_across_engines_preserving_order() {
+            let a = StubEngine(vec!["https://a.example/1".to_string(), "https://b.example/1".to_string()]);
+            let b = StubEngine(vec!["https://b.example/1".to_string(), "https://c.example/1".to_string()]);
+
+            let merged = merge_results(vec![Ok(a.extract_links("")), Ok(b.extract_links(""))]).unwrap();
+
+            assert_eq!(
+                merged,
+                vec![
+                    "https://a.example/1".to_string(),
+                    "https://b.example/1".to_string(),
+                    "https://c.example/1".to_string(),
+                ]
+            );
+        }
+
+        #[test]
+        fn merge_results_skips_failing_engines() {
+            let a = StubEngine(vec!["https://a.example/1".to_string()]);
+
+            let merged = merge_results(vec![Ok(a.extract_links("")), Err(Error::Timeout)]).unwrap();
+
+            assert_eq!(merged, vec!["https://a.example/1".to_string()]);
+        }
+
+        #[test]
+        fn merge_results_fails_only_if_every_engine_fails() {
+            let result = merge_results(vec![Err(Error::Timeout), Err(Error::InvalidResponse)]);
+            assert!(result.is_err());
         }
     }
 }
@@ -120,6 +745,32 @@ pub mod intermediary {
         &url[..i]
     }
 
+    /// Extract and de-duplicate the gleam.io links out of a fetched page body.
+    fn extract_gleam_links(mut body: &str) -> Vec<String> {
+        let mut rep = Vec::new();
+        while get_all_after(body, "https://gleam.io/") != "" {
+            let url = get_url(get_all_after(body, "https://gleam.io/")).to_string();
+            body = get_all_after(body, "https://gleam.io/");
+            let url = if url.len() >= 20 {
+                format!("https://gleam.io/{}", &url[..20])
+            } else if !url.is_empty() {
+                format!("https://gleam.io/{}", url)
+            } else {
+                continue;
+            };
+            if !rep.contains(&url) {
+                rep.push(url);
+            }
+        }
+        let mut final_rep = Vec::new();
+        for url in rep {
+            if let Some(id) = get_gleam_id(&url) {
+                final_rep.push(format!("https://gleam.io/{}/-", id));
+            }
+        }
+        final_rep
+    }
+
     pub fn resolve(url: &str) -> Result<Vec<String>, Error> {
         match minreq::get(url)
             .with_header("Accept", "text/html,text/plain")
@@ -134,29 +785,8 @@ pub mod intermediary {
             .send()
         {
             Ok(response) => {
-                if let Ok(mut body) = response.as_str() {
-                    let mut rep = Vec::new();
-                    while get_all_after(&body, "https://gleam.io/") != "" {
-                        let url = get_url(get_all_after(&body, "https://gleam.io/"));
-                        body = get_all_after(&body, "https://gleam.io/");
-                        let url = if url.len() >= 20 {
-                            format!("https://gleam.io/{}", &url[..20])
-                        } else if !url.is_empty() {
-                            format!("https://gleam.io/{}", url)
-                        } else {
-                            continue;
-                        };
-                        if !rep.contains(&url) {
-                            rep.push(url);
-                        }
-                    }
-                    let mut final_rep = Vec::new();
-                    for url in rep {
-                        if let Some(id) = get_gleam_id(&url) {
-                            final_rep.push(format!("https://gleam.io/{}/-", id));
-                        }
-                    }
-                    Ok(final_rep)
+                if let Ok(body) = response.as_str() {
+                    Ok(extract_gleam_links(body))
                 } else {
                     Err(Error::InvalidResponse)
                 }
@@ -167,6 +797,54 @@ pub mod intermediary {
         }
     }
 
+    /// Same as [`resolve`], but routed through a [`Session`](crate::session::Session) so
+    /// cookies are kept, the `User-Agent` rotates, and soft blocks (429/5xx) are retried
+    /// with backoff instead of immediately failing.
+    pub fn resolve_with_session(
+        session: &mut crate::session::Session,
+        url: &str,
+    ) -> Result<Vec<String>, Error> {
+        let body = session.get(url, &[("Accept", "text/html,text/plain")])?;
+        Ok(extract_gleam_links(&body))
+    }
+
+    /// Same as [`resolve`], but consults `cache` first and only re-requests `url` when no
+    /// resolution for it is cached or the cached one is older than the cache's TTL. The
+    /// freshly resolved links are stored back into
+    /// `cache`. Requires the `cache` feature.
+    #[cfg(feature = "cache")]
+    pub fn resolve_cached(
+        cache: &mut crate::cache::Cache,
+        url: &str,
+    ) -> Result<Vec<String>, Error> {
+        if let Some(links) = cache.get_resolution(url) {
+            return Ok(links.clone());
+        }
+        let links = resolve(url)?;
+        cache.insert_resolution(url.to_string(), links.clone());
+        Ok(links)
+    }
+
+    /// Async equivalent of [`resolve`], backed by `reqwest` instead of `minreq`.
+    /// Requires the `async` feature.
+    #[cfg(feature = "async")]
+    pub async fn resolve_async(url: &str) -> Result<Vec<String>, Error> {
+        let response = reqwest::Client::new()
+            .get(url)
+            .header("Accept", "text/html,text/plain")
+            .header(
+                "User-Agent",
+                "Mozilla/5.0 (X11; Ubuntu; Linux x86_64; rv:78.0) Gecko/20100101 Firefox/78.0",
+            )
+            .header("Host", get_all_between(url, "://", "/"))
+            .send()
+            .await
+            .map_err(|_| Error::Timeout)?;
+
+        let body = response.text().await.map_err(|_| Error::InvalidResponse)?;
+        Ok(extract_gleam_links(&body))
+    }
+
     #[cfg(test)]
     mod test {
         use super::resolve;
@@ -180,6 +858,7 @@ pub mod intermediary {
 
 /// Contains giveaways fetcher
 pub mod gleam {
+    use super::progress::{ProgressEvent, ProgressObserver};
     use super::Error;
     use serde_json::{from_str, Value};
     use std::thread::sleep;
@@ -199,14 +878,61 @@ pub mod gleam {
         None
     }
 
+    /// A single way to earn entries in a gleam.io giveaway (e.g. following a Twitter account
+    /// or visiting a link), as described by one entry of the campaign's `entry_methods` array.
+    #[derive(Debug, Clone)]
+    #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+    pub struct EntryMethod {
+        pub entry_type: String,
+        /// The platform this entry method acts on, e.g. "twitter" or "youtube", when gleam.io
+        /// reports one.
+        pub provider: Option<String>,
+        pub worth: u64,
+        /// Whether completing this entry method is required to enter the giveaway at all.
+        pub mandatory: bool,
+        /// The action's target, e.g. the URL to visit or follow, when gleam.io reports one.
+        pub config: Option<String>,
+        /// The human-readable label gleam.io displays for this entry method.
+        pub text: Option<String>,
+    }
+
+    impl EntryMethod {
+        /// Rebuild the `(entry_type, worth)` tuple produced by earlier versions of this crate.
+        pub fn as_tuple(&self) -> (String, u64) {
+            (self.entry_type.clone(), self.worth)
+        }
+    }
+
+    /// Build an [`EntryMethod`] from one entry of a campaign's `entry_methods` JSON array,
+    /// falling back to `url`/`additional_instruction` when `config`/`text` are absent.
+    fn entry_method_from_json(entry_method: &Value) -> Result<EntryMethod, Error> {
+        Ok(EntryMethod {
+            entry_type: entry_method["entry_type"]
+                .as_str()
+                .ok_or(Error::InvalidResponse)?
+                .to_string(),
+            worth: entry_method["worth"].as_u64().ok_or(Error::InvalidResponse)?,
+            provider: entry_method["provider"].as_str().map(|s| s.to_string()),
+            mandatory: entry_method["mandatory"].as_bool().unwrap_or(false),
+            config: entry_method["config"]
+                .as_str()
+                .or_else(|| entry_method["url"].as_str())
+                .map(|s| s.to_string()),
+            text: entry_method["text"]
+                .as_str()
+                .or_else(|| entry_method["additional_instruction"].as_str())
+                .map(|s| s.to_string()),
+        })
+    }
+
     /// A simple struct used to store informations about a gleam.io giveaway.
     /// Can be serialized by activing the feature "serde-support"
-    #[derive(Debug)]
+    #[derive(Debug, Clone)]
     #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
     pub struct Giveaway {
         pub gleam_id: String,
         pub entry_count: Option<u64>,
-        pub entry_methods: Vec<(String, u64)>,
+        pub entry_methods: Vec<EntryMethod>,
         pub start_date: u64,
         pub end_date: u64,
         pub update_date: u64,
@@ -239,95 +965,150 @@ pub mod gleam {
                 .send()
             {
                 if let Ok(body) = response.as_str() {
-                    if let Some(json) = get_all_between_strict(
-                        body,
-                        "<div class='popup-blocks-container' ng-init='initCampaign(",
-                        ")'>",
-                    ) {
-                        let json = json.replace("&quot;", "\"");
-                        if let Ok(json) = from_str::<Value>(&json) {
-                            if let (
-                                Some(campaign),
-                                Some(incentives),
-                                Some(entry_methods_json),
-                            ) = (
-                                json["campaign"].as_object(),
-                                json["incentive"].as_object(),
-                                json["entry_methods"].as_array(),
-                            ) {
-                                let entry_count: Option<u64> = if let Some(entry_count) =
-                                    get_all_between_strict(body, "initEntryCount(", ")")
-                                {
-                                    if let Ok(entry_count) = entry_count.parse() {
-                                        Some(entry_count)
-                                    } else {
-                                        None
-                                    }
-                                } else {
-                                    None
-                                };
-
-                                let mut entry_methods = Vec::new();
-                                for entry_method in entry_methods_json {
-                                    entry_methods.push((
-                                        entry_method["entry_type"]
-                                            .as_str()
-                                            .ok_or(Error::InvalidResponse)?
-                                            .to_string(),
-                                        entry_method["worth"]
-                                            .as_u64()
-                                            .ok_or(Error::InvalidResponse)?,
-                                    ))
-                                }
+                    Giveaway::parse(giveaway_id, body)
+                } else {
+                    Err(Error::InvalidResponse)
+                }
+            } else {
+                Err(Error::Timeout)
+            }
+        }
 
-                                let mut description = incentives["description"]
-                                    .as_str()
-                                    .ok_or(Error::InvalidResponse)?
-                                    .to_string();
-                                while let Some((begin, end)) =
-                                    get_idx_between_strict(&description, "<", ">")
-                                {
-                                    description.replace_range(begin - 1..end + 1, "");
-                                }
-                                description = description.replace("\u{a0}", "\n");
-                                description = description.replace("&#39;", "'");
-                                
-                                return Ok(Giveaway {
-                                    gleam_id: giveaway_id.to_string(),
-                                    name: campaign["name"]
-                                        .as_str()
-                                        .map(|s| s.to_string())
-                                        .ok_or(Error::InvalidResponse)?,
-                                    description,
-                                    entry_methods,
-                                    start_date: campaign["starts_at"]
-                                        .as_u64()
-                                        .ok_or(Error::InvalidResponse)?,
-                                    end_date: campaign["ends_at"]
-                                        .as_u64()
-                                        .ok_or(Error::InvalidResponse)?,
-                                    update_date: SystemTime::now()
-                                        .duration_since(UNIX_EPOCH)
-                                        .unwrap()
-                                        .as_secs(),
-                                    entry_count,
-                                });
+        /// Same as [`Giveaway::fetch`], but routed through a
+        /// [`Session`](crate::session::Session) so cookies are kept, the `User-Agent`
+        /// rotates, and soft blocks (429/5xx) are retried with backoff instead of
+        /// immediately failing.
+        pub fn fetch_with_session(
+            session: &mut crate::session::Session,
+            url: &str,
+        ) -> Result<Giveaway, Error> {
+            let giveaway_id = match get_gleam_id(url) {
+                Some(id) => id,
+                None => return Err(Error::InvalidResponse),
+            };
+            let url = format!("https://gleam.io/{}/-", giveaway_id);
+
+            let body = session.get(
+                &url,
+                &[
+                    ("Accept", "text/html"),
+                    ("DNT", "1"),
+                    ("Connection", "keep-alive"),
+                    ("Upgrade-Insecure-Requests", "1"),
+                    ("TE", "Trailers"),
+                ],
+            )?;
+            Giveaway::parse(giveaway_id, &body)
+        }
+
+        /// Same as [`Giveaway::fetch`], but consults `cache` first and only hits gleam.io
+        /// when no entry for this giveaway is cached or its `update_date` has exceeded the
+        /// cache's TTL. The freshly fetched giveaway is stored back into `cache`.
+        /// Requires the `cache` feature.
+        #[cfg(feature = "cache")]
+        pub fn fetch_cached(
+            cache: &mut crate::cache::Cache,
+            url: &str,
+        ) -> Result<Giveaway, Error> {
+            let giveaway_id = match get_gleam_id(url) {
+                Some(id) => id,
+                None => return Err(Error::InvalidResponse),
+            };
+            if let Some(giveaway) = cache.get_giveaway(giveaway_id) {
+                return Ok(giveaway.clone());
+            }
+            let giveaway = Giveaway::fetch(url)?;
+            cache.insert_giveaway(giveaway.clone());
+            Ok(giveaway)
+        }
+
+        /// Parse a gleam.io page body already fetched by the caller into a [`Giveaway`].
+        /// Shared by [`Giveaway::fetch`], [`Giveaway::fetch_with_session`] and
+        /// [`Giveaway::fetch_async`].
+        fn parse(giveaway_id: &str, body: &str) -> Result<Giveaway, Error> {
+            if let Some(json) = get_all_between_strict(
+                body,
+                "<div class='popup-blocks-container' ng-init='initCampaign(",
+                ")'>",
+            ) {
+                let json = json.replace("&quot;", "\"");
+                if let Ok(json) = from_str::<Value>(&json) {
+                    if let (Some(campaign), Some(incentives), Some(entry_methods_json)) = (
+                        json["campaign"].as_object(),
+                        json["incentive"].as_object(),
+                        json["entry_methods"].as_array(),
+                    ) {
+                        let entry_count: Option<u64> = if let Some(entry_count) =
+                            get_all_between_strict(body, "initEntryCount(", ")")
+                        {
+                            if let Ok(entry_count) = entry_count.parse() {
+                                Some(entry_count)
+                            } else {
+                                None
                             }
+                        } else {
+                            None
+                        };
+
+                        let mut entry_methods = Vec::new();
+                        for entry_method in entry_methods_json {
+                            entry_methods.push(entry_method_from_json(entry_method)?);
                         }
+
+                        let mut description = incentives["description"]
+                            .as_str()
+                            .ok_or(Error::InvalidResponse)?
+                            .to_string();
+                        while let Some((begin, end)) =
+                            get_idx_between_strict(&description, "<", ">")
+                        {
+                            description.replace_range(begin - 1..end + 1, "");
+                        }
+                        description = description.replace("\u{a0}", "\n");
+                        description = description.replace("&#39;", "'");
+
+                        return Ok(Giveaway {
+                            gleam_id: giveaway_id.to_string(),
+                            name: campaign["name"]
+                                .as_str()
+                                .map(|s| s.to_string())
+                                .ok_or(Error::InvalidResponse)?,
+                            description,
+                            entry_methods,
+                            start_date: campaign["starts_at"]
+                                .as_u64()
+                                .ok_or(Error::InvalidResponse)?,
+                            end_date: campaign["ends_at"]
+                                .as_u64()
+                                .ok_or(Error::InvalidResponse)?,
+                            update_date: SystemTime::now()
+                                .duration_since(UNIX_EPOCH)
+                                .unwrap()
+                                .as_secs(),
+                            entry_count,
+                        });
                     }
                 }
-                Err(Error::InvalidResponse)
-            } else {
-                Err(Error::Timeout)
             }
+            Err(Error::InvalidResponse)
         }
 
-        /// Fetch some urls and wait a cooldown between each request
-        pub fn fetch_vec(urls: Vec<&str>, cooldown: u64) -> Vec<Giveaway> {
+        /// Fetch some urls and wait a cooldown between each request, reporting a
+        /// [`ProgressEvent::GiveawayFetched`] to `observer` after each successful fetch.
+        pub fn fetch_vec(
+            urls: Vec<&str>,
+            cooldown: u64,
+            mut observer: Option<&mut dyn ProgressObserver>,
+        ) -> Vec<Giveaway> {
             let mut giveaways = Vec::new();
 
             for url in &urls {
                 if let Ok(giveaway) = Giveaway::fetch(url) {
+                    if let Some(observer) = &mut observer {
+                        observer.on_event(ProgressEvent::GiveawayFetched {
+                            gleam_id: giveaway.gleam_id.clone(),
+                        });
+                    }
                     giveaways.push(giveaway)
                 }
                 if urls.len() > 1 {
@@ -338,6 +1119,75 @@ pub mod gleam {
             giveaways
         }
 
+        /// Async equivalent of [`Giveaway::fetch`], backed by `reqwest` instead of `minreq`.
+        /// Requires the `async` feature.
+        #[cfg(feature = "async")]
+        pub async fn fetch_async(url: &str) -> Result<Giveaway, Error> {
+            let giveaway_id = match get_gleam_id(url) {
+                Some(id) => id,
+                None => return Err(Error::InvalidResponse),
+            };
+            let url = format!("https://gleam.io/{}/-", giveaway_id);
+
+            let response = reqwest::Client::new()
+                .get(url)
+                .header("Host", "gleam.io")
+                .header(
+                    "User-Agent",
+                    "Mozilla/5.0 (X11; Linux x86_64; rv:72.0) Gecko/20100101 Firefox/72.0",
+                )
+                .header("Accept", "text/html")
+                .header("DNT", "1")
+                .header("Connection", "keep-alive")
+                .header("Upgrade-Insecure-Requests", "1")
+                .header("TE", "Trailers")
+                .send()
+                .await
+                .map_err(|_| Error::Timeout)?;
+
+            let body = response.text().await.map_err(|_| Error::InvalidResponse)?;
+            Giveaway::parse(giveaway_id, &body)
+        }
+
+        /// Fetch many urls concurrently, at most `concurrency` requests in flight at once.
+        /// Results are returned in the same order as `urls`. A `concurrency` of `0` is
+        /// treated as `1` rather than deadlocking every task on an unreleasable permit.
+        /// A [`ProgressEvent::GiveawayFetched`] is reported to `observer`, if any, after each
+        /// successful fetch; it is shared across tasks behind a mutex since they run
+        /// concurrently.
+        /// Requires the `async` feature.
+        #[cfg(feature = "async")]
+        pub async fn fetch_all(
+            urls: Vec<&str>,
+            concurrency: usize,
+            observer: Option<std::sync::Arc<std::sync::Mutex<dyn ProgressObserver + Send>>>,
+        ) -> Vec<Result<Giveaway, Error>> {
+            let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(concurrency.max(1)));
+
+            let tasks = urls.into_iter().map(|url| {
+                let url = url.to_string();
+                let semaphore = semaphore.clone();
+                let observer = observer.clone();
+                async move {
+                    let _permit = semaphore
+                        .acquire()
+                        .await
+                        .expect("semaphore should not be closed");
+                    let result = Giveaway::fetch_async(&url).await;
+                    if let Ok(giveaway) = &result {
+                        if let Some(observer) = &observer {
+                            observer.lock().unwrap().on_event(ProgressEvent::GiveawayFetched {
+                                gleam_id: giveaway.gleam_id.clone(),
+                            });
+                        }
+                    }
+                    result
+                }
+            });
+
+            futures::future::join_all(tasks).await
+        }
+
         /// Return the url
         pub fn get_url(&self) -> String {
             format!("https://gleam.io/{}/-", self.gleam_id)
@@ -357,7 +1207,13 @@ pub mod gleam {
         }
 
         pub fn get_max_entries_per_account(&self) -> u64 {
-            self.entry_methods.iter().map(|e| e.1).sum()
+            self.entry_methods.iter().map(|e| e.worth).sum()
+        }
+
+        /// Rebuild the `Vec<(entry_type, worth)>` shape produced by earlier versions of this
+        /// crate, for callers not yet migrated to [`EntryMethod`].
+        pub fn entry_methods_as_tuples(&self) -> Vec<(String, u64)> {
+            self.entry_methods.iter().map(EntryMethod::as_tuple).collect()
         }
     }
 
@@ -365,6 +1221,38 @@ pub mod gleam {
     mod tests {
         use super::*;
 
+        #[test]
+        fn entry_method_from_json_applies_fallbacks() {
+            let json: Value = from_str(
+                r#"{"entry_type":"visit","worth":3,"config":null,"url":"https://example.com","text":null,"additional_instruction":"Click here"}"#,
+            )
+            .unwrap();
+
+            let entry_method = entry_method_from_json(&json).unwrap();
+
+            assert_eq!(entry_method.entry_type, "visit");
+            assert_eq!(entry_method.worth, 3);
+            assert_eq!(entry_method.provider, None);
+            assert!(!entry_method.mandatory);
+            assert_eq!(entry_method.config, Some("https://example.com".to_string()));
+            assert_eq!(entry_method.text, Some("Click here".to_string()));
+        }
+
+        #[test]
+        fn entry_method_from_json_prefers_primary_fields_over_fallbacks() {
+            let json: Value = from_str(
+                r#"{"entry_type":"twitter_follow","worth":1,"provider":"twitter","mandatory":true,"config":"https://twitter.com/example","url":"https://fallback.example","text":"Follow us","additional_instruction":"ignored"}"#,
+            )
+            .unwrap();
+
+            let entry_method = entry_method_from_json(&json).unwrap();
+
+            assert_eq!(entry_method.provider, Some("twitter".to_string()));
+            assert!(entry_method.mandatory);
+            assert_eq!(entry_method.config, Some("https://twitter.com/example".to_string()));
+            assert_eq!(entry_method.text, Some("Follow us".to_string()));
+        }
+
         #[test]
         fn test_giveaway_struct() {
             let giveaway =
@@ -411,3 +1299,56 @@ pub mod gleam {
         }
     }
 }
+
+/// The search -> resolve steps shown in this crate's top-level example, followed by fetching
+/// every gleam.io giveaway found, wired through a [`Session`](session::Session) so its
+/// observer (see
+/// [`Session::set_observer`](session::Session::set_observer)) reports every step, letting a
+/// CLI or TUI render progress without this crate depending on any particular UI.
+pub mod pipeline {
+    use super::gleam::Giveaway;
+    use super::google;
+    use super::intermediary;
+    use super::progress::ProgressEvent;
+    use super::session::Session;
+
+    /// Search every page in `pages`, resolve every referring link found, and fetch every
+    /// gleam.io giveaway found, reporting every step to the observer set on `session` with
+    /// [`Session::set_observer`](crate::session::Session::set_observer) (if any) — this way
+    /// retry/rate-limit events from `session` and the pipeline's own events share one stream.
+    /// Failed pages, resolutions and giveaways are skipped rather than aborting the run.
+    pub fn run(pages: std::ops::Range<usize>, session: &mut Session) -> Vec<Giveaway> {
+        let mut giveaways = Vec::new();
+        for page in pages {
+            let links = match google::search_with_session(session, page) {
+                Ok(links) => links,
+                Err(_) => continue,
+            };
+            session.notify(ProgressEvent::SearchPageDone {
+                page,
+                links_found: links.len(),
+            });
+
+            for link in links {
+                let gleam_links = match intermediary::resolve_with_session(session, &link) {
+                    Ok(gleam_links) => gleam_links,
+                    Err(_) => continue,
+                };
+                session.notify(ProgressEvent::PageResolved {
+                    url: link,
+                    gleam_links: gleam_links.len(),
+                });
+
+                for gleam_link in gleam_links {
+                    if let Ok(giveaway) = Giveaway::fetch_with_session(session, &gleam_link) {
+                        session.notify(ProgressEvent::GiveawayFetched {
+                            gleam_id: giveaway.gleam_id.clone(),
+                        });
+                        giveaways.push(giveaway);
+                    }
+                }
+            }
+        }
+        giveaways
+    }
+}